@@ -1,5 +1,5 @@
 use std::fmt;
-use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut, AddAssign, SubAssign, MulAssign};
 use std::str::FromStr;
 
 
@@ -13,6 +13,11 @@ pub struct Matrix<T> {
     col: usize,
 }
 
+/// A single-row or single-column `Matrix` used as a vector. Shares the
+/// same row-major storage, so `is_row_vector`/`is_col_vector`/`dot` work
+/// on it without any reshaping.
+pub type Vector<T> = Matrix<T>;
+
 impl<T> Matrix<T> {
     /// Returns the number of rows and columns in the first and second
     /// elements of the tuple, respectively.
@@ -23,6 +28,40 @@ impl<T> Matrix<T> {
     pub fn is_square(&self) -> bool {
         self.row == self.col
     }
+    /// Return whether this matrix has exactly one row.
+    pub fn is_row_vector(&self) -> bool {
+        self.row == 1
+    }
+    /// Return whether this matrix has exactly one column.
+    pub fn is_col_vector(&self) -> bool {
+        self.col == 1
+    }
+    /// Returns an iterator over the elements in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+    /// Returns an iterator over the rows, each yielded as a slice.
+    pub fn iter_rows(&self) -> std::slice::Chunks<'_, T> {
+        self.data.chunks(self.col)
+    }
+    /// Swaps rows `a` and `b` in place.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for j in 0..self.col {
+            self.data.swap(a * self.col + j, b * self.col + j);
+        }
+    }
+    /// Swaps columns `a` and `b` in place.
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for i in 0..self.row {
+            self.data.swap(i * self.col + a, i * self.col + b);
+        }
+    }
 }
 
 impl<T: Copy> Matrix<T> {
@@ -41,6 +80,226 @@ impl<T: Copy> Matrix<T> {
         }
         Matrix { data, row: self.col, col: self.row }
     }
+    /// Returns row `i` as an owned vector.
+    pub fn row(&self, i: usize) -> Vec<T> {
+        self.data[(i * self.col)..((i + 1) * self.col)].to_vec()
+    }
+    /// Returns column `j` as an owned vector.
+    pub fn col(&self, j: usize) -> Vec<T> {
+        (0..self.row).map(|i| self.data[i * self.col + j]).collect()
+    }
+    /// Builds a matrix from a slice of row slices. Returns
+    /// `ParseMatrixError::ColumnsNotAligned` if the rows are not all the
+    /// same length.
+    pub fn from_rows(rows: &[&[T]]) -> Result<Matrix<T>, ParseMatrixError> {
+        let col = rows.first().map_or(0, |r| r.len());
+        let mut data = Vec::with_capacity(rows.len() * col);
+        for r in rows {
+            if r.len() != col {
+                return Err(ParseMatrixError::ColumnsNotAligned);
+            }
+            data.extend_from_slice(r);
+        }
+        Ok(Matrix { data, row: rows.len(), col })
+    }
+}
+
+impl<T: Copy + Zero> Matrix<T> {
+    /// Returns a `row` x `col` matrix of all zeros.
+    pub fn zeros(row: usize, col: usize) -> Matrix<T> {
+        Matrix { data: vec![T::zero(); row * col], row, col }
+    }
+    /// Returns a square matrix with `values` on the diagonal and zeros elsewhere.
+    pub fn from_diagonal(values: &[T]) -> Matrix<T> {
+        let n = values.len();
+        let mut data = vec![T::zero(); n * n];
+        for i in 0..n {
+            data[i * n + i] = values[i];
+        }
+        Matrix { data, row: n, col: n }
+    }
+}
+
+impl<T: Copy + One> Matrix<T> {
+    /// Returns a `row` x `col` matrix of all ones.
+    pub fn ones(row: usize, col: usize) -> Matrix<T> {
+        Matrix { data: vec![T::one(); row * col], row, col }
+    }
+}
+
+impl<T: Copy + Zero + One> Matrix<T> {
+    /// Returns the `n` x `n` identity matrix.
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut data = vec![T::zero(); n * n];
+        for i in 0..n {
+            data[i * n + i] = T::one();
+        }
+        Matrix { data, row: n, col: n }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Matrix<T> {
+    /// Returns the dot product of `self` and `other`, treating both as
+    /// vectors. Both must be a 1xn row vector or an nx1 column vector,
+    /// and must have the same number of elements. Panics otherwise.
+    pub fn dot(&self, other: &Matrix<T>) -> T {
+        if !(self.is_row_vector() || self.is_col_vector()) ||
+            !(other.is_row_vector() || other.is_col_vector()) {
+            panic!();
+        }
+        if self.data.len() != other.data.len() {
+            panic!();
+        }
+        let mut sum = self.data[0] * other.data[0];
+        for i in 1..self.data.len() {
+            sum = sum + self.data[i] * other.data[i];
+        }
+        sum
+    }
+}
+
+
+/// Implement linear-algebra solvers (LU decomposition, determinant and
+/// inverse) for matrices whose elements convert losslessly to `f64`.
+impl<T: Copy + Into<f64>> Matrix<T> {
+    /// Decomposes the matrix into an LU factorization with partial pivoting.
+    ///
+    /// Returns `(lu, perm, sign)` where `lu` packs the upper triangle `U`
+    /// on and above the diagonal and the multipliers of the lower triangle
+    /// `L` below it (with an implicit unit diagonal), `perm` is the row
+    /// permutation applied during pivoting, and `sign` is `1.0` or `-1.0`
+    /// depending on the parity of that permutation. Panics if the matrix
+    /// is not square.
+    pub fn lu(&self) -> (Matrix<f64>, Vec<usize>, f64) {
+        if !self.is_square() {
+            panic!();
+        }
+        let n = self.row;
+        let mut a: Vec<f64> = self.data.iter().map(|&x| x.into()).collect();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+        for k in 0..n {
+            let mut p = k;
+            let mut max_val = a[k * n + k].abs();
+            for i in (k + 1)..n {
+                let v = a[i * n + k].abs();
+                if v > max_val {
+                    max_val = v;
+                    p = i;
+                }
+            }
+            if p != k {
+                for j in 0..n {
+                    a.swap(k * n + j, p * n + j);
+                }
+                perm.swap(k, p);
+                sign = -sign;
+            }
+            let pivot = a[k * n + k];
+            if pivot.abs() < 1e-12 {
+                continue;
+            }
+            for i in (k + 1)..n {
+                let m = a[i * n + k] / pivot;
+                a[i * n + k] = m;
+                for j in (k + 1)..n {
+                    a[i * n + j] -= m * a[k * n + j];
+                }
+            }
+        }
+        (Matrix { data: a, row: n, col: n }, perm, sign)
+    }
+
+    /// Returns the determinant of the matrix, computed from its LU
+    /// decomposition as `sign * product(diagonal pivots)`. Returns `0.0`
+    /// if the matrix is singular. Panics if the matrix is not square.
+    pub fn determinant(&self) -> f64 {
+        let (lu, _perm, sign) = self.lu();
+        let mut det = sign;
+        for i in 0..self.row {
+            det *= lu[(i, i)];
+        }
+        if det.abs() < 1e-9 {
+            0.0
+        } else {
+            det
+        }
+    }
+
+    /// Returns the inverse of the matrix computed by Gauss-Jordan
+    /// elimination on the matrix augmented with an identity block, or
+    /// `None` if the matrix is singular. Panics if the matrix is not
+    /// square.
+    pub fn inverse(&self) -> Option<Matrix<f64>> {
+        if !self.is_square() {
+            panic!();
+        }
+        let n = self.row;
+        let mut a: Vec<f64> = self.data.iter().map(|&x| x.into()).collect();
+        let mut inv = vec![0.0; n * n];
+        for i in 0..n {
+            inv[i * n + i] = 1.0;
+        }
+        for k in 0..n {
+            let mut p = k;
+            let mut max_val = a[k * n + k].abs();
+            for i in (k + 1)..n {
+                let v = a[i * n + k].abs();
+                if v > max_val {
+                    max_val = v;
+                    p = i;
+                }
+            }
+            if max_val < 1e-12 {
+                return None;
+            }
+            if p != k {
+                for j in 0..n {
+                    a.swap(k * n + j, p * n + j);
+                    inv.swap(k * n + j, p * n + j);
+                }
+            }
+            let pivot = a[k * n + k];
+            for j in 0..n {
+                a[k * n + j] /= pivot;
+                inv[k * n + j] /= pivot;
+            }
+            for i in 0..n {
+                if i != k {
+                    let m = a[i * n + k];
+                    if m != 0.0 {
+                        for j in 0..n {
+                            a[i * n + j] -= m * a[k * n + j];
+                            inv[i * n + j] -= m * inv[k * n + j];
+                        }
+                    }
+                }
+            }
+        }
+        Some(Matrix { data: inv, row: n, col: n })
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T> + Zero + One> Matrix<T> {
+    /// Returns `self` raised to the `exp`-th power via binary
+    /// exponentiation, in `O(n^3 log exp)`. `exp == 0` returns the
+    /// identity matrix. Panics if the matrix is not square.
+    pub fn pow(&self, mut exp: u64) -> Matrix<T> {
+        if !self.is_square() {
+            panic!();
+        }
+        let n = self.row;
+        let mut acc = Matrix::identity(n);
+        let mut base = Matrix { data: self.data.clone(), row: n, col: n };
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = &acc * &base;
+            }
+            base = &base * &base;
+            exp >>= 1;
+        }
+        acc
+    }
 }
 
 
@@ -71,6 +330,94 @@ impl_is_identity!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize; 0, 1);
 impl_is_identity!(f32 f64; 0.0, 1.0);
 
 
+/// Additive and multiplicative identities, needed to fabricate the
+/// identity matrix that seeds `Matrix::pow`'s binary exponentiation.
+pub trait Zero {
+    fn zero() -> Self;
+}
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! impl_zero_one {
+    ($($T: ty)*; $zero: expr, $one: expr) => {$(
+        impl Zero for $T {
+            fn zero() -> Self { $zero }
+        }
+        impl One for $T {
+            fn one() -> Self { $one }
+        }
+    )*}
+}
+
+impl_zero_one!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize; 0, 1);
+impl_zero_one!(f32 f64; 0.0, 1.0);
+
+
+/// A scalar that performs all arithmetic modulo the compile-time constant
+/// `M`, so `Matrix<ModInt<M>>` can represent linear-recurrence and
+/// graph-reachability transition matrices and be raised to a power with
+/// `Matrix::pow` without overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    /// Returns the canonical representative of this value in `0..M`.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(x: i64) -> Self {
+        let m = M as i64;
+        ModInt { value: (((x % m) + m) % m) as u64 }
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = ModInt<M>;
+    fn add(self, rhs: Self) -> Self::Output {
+        ModInt { value: (self.value + rhs.value) % M }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = ModInt<M>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        ModInt { value: (self.value + M - rhs.value) % M }
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = ModInt<M>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        ModInt { value: ((self.value as u128 * rhs.value as u128) % M as u128) as u64 }
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = ModInt<M>;
+    fn neg(self) -> Self::Output {
+        ModInt { value: (M - self.value) % M }
+    }
+}
+
+impl<const M: u64> Zero for ModInt<M> {
+    fn zero() -> Self {
+        ModInt::from(0)
+    }
+}
+
+impl<const M: u64> One for ModInt<M> {
+    fn one() -> Self {
+        ModInt::from(1)
+    }
+}
+
+
 /// Implement Display trait for Matrix
 impl<T: fmt::Display> fmt::Display for Matrix<T> {
     /// Outputs using `write!(f, ...)`.
@@ -330,6 +677,97 @@ impl_op_refs!(Mul, mul, T<>, T; Mul<Output=T>; );
 impl_op_refs!(Div, div, T<>, f64; ; Into<f64>);
 
 
+impl<'a, T: Add<Output = T> + Copy> AddAssign<&'a Matrix<T>> for Matrix<T> {
+    /// Adds `rhs` into `self` in place. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn add_assign(&mut self, rhs: &'a Matrix<T>) {
+        if self.row != rhs.row || self.col != rhs.col {
+            panic!();
+        }
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a = *a + *b;
+        }
+    }
+}
+
+impl<'a, T: Add<Output = T> + Copy> AddAssign<&'a T> for Matrix<T> {
+    /// Adds scalar `rhs` into every element of `self` in place.
+    fn add_assign(&mut self, rhs: &'a T) {
+        for a in self.data.iter_mut() {
+            *a = *a + *rhs;
+        }
+    }
+}
+
+impl<'a, T: Sub<Output = T> + Copy> SubAssign<&'a Matrix<T>> for Matrix<T> {
+    /// Subtracts `rhs` from `self` in place. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn sub_assign(&mut self, rhs: &'a Matrix<T>) {
+        if self.row != rhs.row || self.col != rhs.col {
+            panic!();
+        }
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a = *a - *b;
+        }
+    }
+}
+
+impl<'a, T: Sub<Output = T> + Copy> SubAssign<&'a T> for Matrix<T> {
+    /// Subtracts scalar `rhs` from every element of `self` in place.
+    fn sub_assign(&mut self, rhs: &'a T) {
+        for a in self.data.iter_mut() {
+            *a = *a - *rhs;
+        }
+    }
+}
+
+impl<'a, T: Mul<Output = T> + Copy> MulAssign<&'a T> for Matrix<T> {
+    /// Multiplies every element of `self` by scalar `rhs` in place.
+    fn mul_assign(&mut self, rhs: &'a T) {
+        for a in self.data.iter_mut() {
+            *a = *a * *rhs;
+        }
+    }
+}
+
+impl<'a, T: Add<Output = T> + Mul<Output = T> + Copy> MulAssign<&'a Matrix<T>> for Matrix<T> {
+    /// Multiplies `self` by `rhs` in place. Requires `rhs` to be square with
+    /// `rhs.row == self.col`, so the product keeps `self`'s shape. Panics otherwise.
+    fn mul_assign(&mut self, rhs: &'a Matrix<T>) {
+        if self.col != rhs.row || rhs.row != rhs.col {
+            panic!();
+        }
+        let product = &*self * rhs;
+        self.data = product.data;
+    }
+}
+
+
+/// Implement compound-assignment Oprators with owned-Rhs scenarios,
+/// forwarding to the by-reference impls above. Same macro shape as
+/// `impl_op_refs!`, restricted to the single `(M, Rhs)` combination that
+/// assignment needs.
+macro_rules! impl_opassign_refs {
+    (
+        $Op:ident, $op:ident, $Rhs:ident < $($params:ident),* $(,)* >;
+        $($traits_vals:ident < $($keys:ident = $values:ident),* $(,)* >),*;
+        $($traits:ident < $($args:ident),* $(,)* >),*
+    ) => {
+        impl<T: Copy $(+ $traits_vals<$($keys = $values),*>)* $(+ $traits<$($args),*>)*> $Op<$Rhs <$($params),*> > for Matrix<T> {
+            fn $op(&mut self, rhs: $Rhs <$($params),*>) {
+                self.$op(&rhs)
+            }
+        }
+    }
+}
+
+
+impl_opassign_refs!(AddAssign, add_assign, Matrix<T>; Add<Output=T>; );
+impl_opassign_refs!(SubAssign, sub_assign, Matrix<T>; Sub<Output=T>; );
+impl_opassign_refs!(MulAssign, mul_assign, Matrix<T>; Mul<Output=T>, Add<Output=T>; );
+impl_opassign_refs!(AddAssign, add_assign, T<>; Add<Output=T>; );
+impl_opassign_refs!(SubAssign, sub_assign, T<>; Sub<Output=T>; );
+impl_opassign_refs!(MulAssign, mul_assign, T<>; Mul<Output=T>; );
+
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -403,4 +841,107 @@ mod tests {
         let m = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
         assert_eq!(m.transposition(), Matrix::new(3, 2, &[-2, 1, -1, 2, 0, 3]));
     }
+    #[test]
+    fn linear_algebra() {
+        use super::Matrix;
+
+        let a = Matrix::new(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+        assert!((a.determinant() - 4.0).abs() < 1e-9);
+
+        let inv = a.inverse().unwrap();
+        let identity = &a * &inv;
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((identity[(i, j)] - expected).abs() < 1e-9);
+            }
+        }
+
+        let singular = Matrix::new(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(singular.determinant(), 0.0);
+        assert_eq!(singular.inverse(), None);
+    }
+    #[test]
+    fn pow_and_mod_int() {
+        use super::{Matrix, ModInt};
+
+        let fib = Matrix::new(2, 2, &[1, 1, 1, 0]);
+        assert_eq!(fib.pow(0), Matrix::new(2, 2, &[1, 0, 0, 1]));
+        assert_eq!(fib.pow(1), fib);
+        // F(10) = 55
+        assert_eq!(fib.pow(10)[(0, 1)], 55);
+
+        let m = Matrix::new(
+            2, 2,
+            &[ModInt::<998244353>::from(1), ModInt::from(1), ModInt::from(1), ModInt::from(0)],
+        );
+        let p = m.pow(10);
+        assert_eq!(p[(0, 1)].value(), 55);
+
+        let a = ModInt::<998244353>::from(998244352);
+        let b = ModInt::<998244353>::from(2);
+        assert_eq!((a + b).value(), 1);
+        assert_eq!((a * b).value(), 998244351);
+        assert_eq!((-a).value(), 1);
+    }
+    #[test]
+    fn compound_assignment() {
+        use super::Matrix;
+
+        let mut a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+        let b = Matrix::new(2, 2, &[10, 20, 30, 40]);
+
+        a += &b;
+        assert_eq!(a, Matrix::new(2, 2, &[11, 22, 33, 44]));
+
+        a -= &b;
+        assert_eq!(a, Matrix::new(2, 2, &[1, 2, 3, 4]));
+
+        a += 1;
+        assert_eq!(a, Matrix::new(2, 2, &[2, 3, 4, 5]));
+
+        a *= 2;
+        assert_eq!(a, Matrix::new(2, 2, &[4, 6, 8, 10]));
+
+        let identity = Matrix::new(2, 2, &[1, 0, 0, 1]);
+        a *= &identity;
+        assert_eq!(a, Matrix::new(2, 2, &[4, 6, 8, 10]));
+    }
+    #[test]
+    fn views_iterators_and_vectors() {
+        use super::{Matrix, Vector};
+
+        let mut m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.iter_rows().collect::<Vec<&[i32]>>(), vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(m.row(1), vec![4, 5, 6]);
+        assert_eq!(m.col(1), vec![2, 5]);
+
+        m.swap_rows(0, 1);
+        assert_eq!(m, Matrix::new(2, 3, &[4, 5, 6, 1, 2, 3]));
+
+        m.swap_cols(0, 2);
+        assert_eq!(m, Matrix::new(2, 3, &[6, 5, 4, 3, 2, 1]));
+
+        let row: Vector<i32> = Matrix::new(1, 3, &[1, 2, 3]);
+        let col: Vector<i32> = Matrix::new(3, 1, &[4, 5, 6]);
+        assert!(row.is_row_vector());
+        assert!(col.is_col_vector());
+        assert_eq!(row.dot(&col), 32);
+    }
+    #[test]
+    fn named_constructors() {
+        use super::{Matrix, ParseMatrixError};
+
+        assert_eq!(Matrix::<i32>::identity(3), Matrix::new(3, 3, &[1, 0, 0, 0, 1, 0, 0, 0, 1]));
+        assert_eq!(Matrix::<i32>::zeros(2, 3), Matrix::new(2, 3, &[0, 0, 0, 0, 0, 0]));
+        assert_eq!(Matrix::<i32>::ones(2, 3), Matrix::new(2, 3, &[1, 1, 1, 1, 1, 1]));
+        assert_eq!(Matrix::from_diagonal(&[1, 2, 3]), Matrix::new(3, 3, &[1, 0, 0, 0, 2, 0, 0, 0, 3]));
+
+        let rows: [&[i32]; 2] = [&[1, 2, 3], &[4, 5, 6]];
+        assert_eq!(Matrix::from_rows(&rows), Ok(Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6])));
+
+        let ragged: [&[i32]; 2] = [&[1, 2, 3], &[4, 5]];
+        assert_eq!(Matrix::from_rows(&ragged), Err(ParseMatrixError::ColumnsNotAligned));
+    }
 }